@@ -0,0 +1,154 @@
+use crate::dataframe::values::{Column, NuDataFrame, NuLazyFrame};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use polars::prelude::MeltArgs;
+
+#[derive(Clone)]
+pub struct MeltDF;
+
+impl Command for MeltDF {
+    fn name(&self) -> &str {
+        "melt"
+    }
+
+    fn usage(&self) -> &str {
+        "Unpivot a dataframe from wide to long format"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required_named(
+                "id-columns",
+                SyntaxShape::Table,
+                "Columns to keep as identifiers, repeated for every melted row",
+                Some('i'),
+            )
+            .required_named(
+                "value-columns",
+                SyntaxShape::Table,
+                "Columns whose values are melted into the new value column",
+                Some('v'),
+            )
+            .named(
+                "variable-name",
+                SyntaxShape::String,
+                "Name for the resulting variable column, defaults to \"variable\"",
+                None,
+            )
+            .named(
+                "value-name",
+                SyntaxShape::String,
+                "Name for the resulting value column, defaults to \"value\"",
+                None,
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Melt a dataframe from wide to long format",
+            example: "[[a b c]; [1 2 3] [4 5 6]] | to-df | melt -i [a] -v [b c]",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new(
+                        "a".to_string(),
+                        vec![
+                            Value::test_int(1),
+                            Value::test_int(4),
+                            Value::test_int(1),
+                            Value::test_int(4),
+                        ],
+                    ),
+                    Column::new(
+                        "variable".to_string(),
+                        vec![
+                            Value::test_string("b"),
+                            Value::test_string("b"),
+                            Value::test_string("c"),
+                            Value::test_string("c"),
+                        ],
+                    ),
+                    Column::new(
+                        "value".to_string(),
+                        vec![
+                            Value::test_int(2),
+                            Value::test_int(5),
+                            Value::test_int(3),
+                            Value::test_int(6),
+                        ],
+                    ),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let id_columns: Vec<Value> = call.req_named(engine_state, stack, "id-columns")?;
+        let value_columns: Vec<Value> = call.req_named(engine_state, stack, "value-columns")?;
+        let variable_name: Option<String> =
+            call.get_flag(engine_state, stack, "variable-name")?;
+        let value_name: Option<String> = call.get_flag(engine_state, stack, "value-name")?;
+
+        let id_vars = column_names(id_columns)?;
+        let value_vars = column_names(value_columns)?;
+
+        let args = MeltArgs {
+            id_vars,
+            value_vars,
+            variable_name,
+            value_name,
+        };
+
+        let value = input.into_value(call.head);
+        let lazy = NuLazyFrame::try_from_value(value)?;
+        let from_eager = lazy.from_eager;
+
+        let melted = lazy.into_polars().melt(args);
+        let lazy = NuLazyFrame::new(from_eager, melted);
+
+        if from_eager {
+            let df = NuDataFrame::try_from(lazy)?;
+            Ok(PipelineData::Value(df.into_value(call.head), None))
+        } else {
+            Ok(PipelineData::Value(lazy.into_value(call.head), None))
+        }
+    }
+}
+
+fn column_names(values: Vec<Value>) -> Result<Vec<String>, ShellError> {
+    values
+        .into_iter()
+        .map(|value| value.as_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(vec![Box::new(MeltDF {})])
+    }
+}
@@ -0,0 +1,154 @@
+use crate::dataframe::values::{Column, NuDataFrame, NuLazyFrame};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+use polars::prelude::PivotAgg;
+
+#[derive(Clone)]
+pub struct PivotDF;
+
+impl Command for PivotDF {
+    fn name(&self) -> &str {
+        "pivot"
+    }
+
+    fn usage(&self) -> &str {
+        "Pivot a dataframe from long to wide format"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required_named(
+                "index",
+                SyntaxShape::Table,
+                "Columns to keep as the new row index",
+                Some('i'),
+            )
+            .required_named(
+                "columns",
+                SyntaxShape::String,
+                "Column whose unique values become the new columns",
+                Some('c'),
+            )
+            .required_named(
+                "values",
+                SyntaxShape::String,
+                "Column whose values fill the pivoted cells",
+                Some('v'),
+            )
+            .named(
+                "aggregate",
+                SyntaxShape::String,
+                "Aggregation applied to values that collide in a pivoted cell: first, sum, min, max, mean, median, count, last. Defaults to \"first\"",
+                Some('a'),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Pivot a dataframe from long to wide format",
+            example: "[[a b c]; [x one 1] [x two 2] [y one 3]] | to-df | pivot -i [a] -c b -v c",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new(
+                        "a".to_string(),
+                        vec![Value::test_string("x"), Value::test_string("y")],
+                    ),
+                    Column::new(
+                        "one".to_string(),
+                        vec![Value::test_int(1), Value::test_int(3)],
+                    ),
+                    Column::new(
+                        "two".to_string(),
+                        vec![Value::test_int(2), Value::test_nothing()],
+                    ),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let index: Vec<nu_protocol::Value> = call.req_named(engine_state, stack, "index")?;
+        let columns: Spanned<String> = call.req_named(engine_state, stack, "columns")?;
+        let values: Spanned<String> = call.req_named(engine_state, stack, "values")?;
+        let aggregate: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "aggregate")?;
+
+        let index = index
+            .into_iter()
+            .map(|value| value.as_string())
+            .collect::<Result<Vec<String>, ShellError>>()?;
+
+        let agg = match aggregate {
+            None => PivotAgg::First,
+            Some(ref name) => match name.item.as_str() {
+                "first" => PivotAgg::First,
+                "sum" => PivotAgg::Sum,
+                "min" => PivotAgg::Min,
+                "max" => PivotAgg::Max,
+                "mean" => PivotAgg::Mean,
+                "median" => PivotAgg::Median,
+                "count" => PivotAgg::Count,
+                "last" => PivotAgg::Last,
+                _ => {
+                    return Err(ShellError::IncompatibleParametersSingle(
+                        "Unsupported aggregation, expected one of first, sum, min, max, mean, median, count, last".into(),
+                        name.span,
+                    ))
+                }
+            },
+        };
+
+        let value = input.into_value(call.head);
+        let lazy = NuLazyFrame::try_from_value(value)?;
+        let df = NuDataFrame::try_from(lazy)?;
+
+        let pivoted = df
+            .as_ref()
+            .pivot_stable(vec![values.item], index, vec![columns.item], agg)
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Error pivoting dataframe".into(),
+                    e.to_string(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        let df = NuDataFrame::new(pivoted);
+        Ok(PipelineData::Value(df.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(vec![Box::new(PivotDF {})])
+    }
+}
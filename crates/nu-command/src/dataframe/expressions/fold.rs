@@ -0,0 +1,116 @@
+use crate::dataframe::values::{Column, NuDataFrame, NuExpression};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+use polars::prelude::{fold_exprs, Expr};
+
+#[derive(Clone)]
+pub struct ExprFold;
+
+impl Command for ExprFold {
+    fn name(&self) -> &str {
+        "fold"
+    }
+
+    fn usage(&self) -> &str {
+        "Horizontally accumulate a binary operator across a list of column expressions"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "acc",
+                SyntaxShape::Any,
+                "Initial accumulator expression, seeds the first reduction",
+            )
+            .required(
+                "operator",
+                SyntaxShape::String,
+                "Binary operator applied left-to-right across the columns: +, -, *, /, min, max",
+            )
+            .required(
+                "columns",
+                SyntaxShape::Table,
+                "List of column expressions folded into the accumulator, in order",
+            )
+            .category(Category::Custom("expression".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Row-wise sum of three columns",
+            example: r#"[[a b c]; [1 2 3] [4 5 6]]
+    | to-df
+    | select (fold (lit 0) + [a b c] | as "total")"#,
+            result: Some(
+                NuDataFrame::try_from_columns(vec![Column::new(
+                    "total".to_string(),
+                    vec![Value::test_int(6), Value::test_int(15)],
+                )])
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Any
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("expression".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let acc: Value = call.req(engine_state, stack, 0)?;
+        let operator: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let columns: Vec<Value> = call.req(engine_state, stack, 2)?;
+
+        let acc = NuExpression::try_from_value(acc)?.into_polars();
+
+        let exprs: Vec<Expr> = columns
+            .into_iter()
+            .map(|value| NuExpression::try_from_value(value).map(NuExpression::into_polars))
+            .collect::<Result<Vec<Expr>, ShellError>>()?;
+
+        let folded = match operator.item.as_str() {
+            "+" => fold_exprs(acc, |a, b| Ok(&a + &b), exprs),
+            "-" => fold_exprs(acc, |a, b| Ok(&a - &b), exprs),
+            "*" => fold_exprs(acc, |a, b| Ok(&a * &b), exprs),
+            "/" => fold_exprs(acc, |a, b| Ok(&a / &b), exprs),
+            "min" => fold_exprs(acc, |a, b| a.zip_with(&a.lt_eq(&b)?, &b), exprs),
+            "max" => fold_exprs(acc, |a, b| a.zip_with(&a.gt_eq(&b)?, &b), exprs),
+            _ => {
+                return Err(ShellError::IncompatibleParametersSingle(
+                    "Unsupported operator, expected one of +, -, *, /, min, max".into(),
+                    operator.span,
+                ))
+            }
+        };
+
+        let expr = NuExpression::new(folded);
+        Ok(PipelineData::Value(expr.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+    use crate::dataframe::expressions::ExprAlias;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(vec![Box::new(ExprFold {}), Box::new(ExprAlias {})])
+    }
+}
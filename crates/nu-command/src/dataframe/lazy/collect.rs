@@ -0,0 +1,210 @@
+use crate::dataframe::values::{Column, NuDataFrame, NuLazyFrame};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct LazyCollect;
+
+impl Command for LazyCollect {
+    fn name(&self) -> &str {
+        "collect"
+    }
+
+    fn usage(&self) -> &str {
+        "Collect a lazy dataframe into an eager one"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .switch(
+                "streaming",
+                "Collect the lazy frame through Polars' streaming engine, processing the query in batches instead of materializing it all at once",
+                None,
+            )
+            .switch(
+                "no-predicate-pushdown",
+                "Disable the predicate pushdown optimization",
+                None,
+            )
+            .switch(
+                "no-projection-pushdown",
+                "Disable the projection pushdown optimization",
+                None,
+            )
+            .switch(
+                "no-slice-pushdown",
+                "Disable the slice pushdown optimization",
+                None,
+            )
+            .switch(
+                "no-cse",
+                "Disable the common subplan elimination optimization",
+                None,
+            )
+            .switch(
+                "no-simplify-expr",
+                "Disable the simplify expression optimization",
+                None,
+            )
+            .named(
+                "with-row-index",
+                SyntaxShape::String,
+                "Name for a row index column added as the first column of the collected dataframe",
+                None,
+            )
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "Starting offset for the row index column, defaults to 0",
+                None,
+            )
+            .category(Category::Custom("lazyframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Collect a lazy dataframe into an eager one",
+                example: "[[a b]; [1 2] [3 4]] | to-lazy | collect",
+                result: None,
+            },
+            Example {
+                description: "Disabling query optimizations does not change the collected result",
+                example: "[[a b]; [1 2] [3 4]] | to-lazy | collect --no-predicate-pushdown --no-projection-pushdown --no-slice-pushdown --no-cse --no-simplify-expr",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new(
+                            "a".to_string(),
+                            vec![Value::test_int(1), Value::test_int(3)],
+                        ),
+                        Column::new(
+                            "b".to_string(),
+                            vec![Value::test_int(2), Value::test_int(4)],
+                        ),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
+            Example {
+                description: "Collect and append a row index column, starting from an offset",
+                example: "[[a b]; [1 2] [3 4]] | to-lazy | collect --with-row-index index --offset 10",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new(
+                            "index".to_string(),
+                            vec![Value::test_int(10), Value::test_int(11)],
+                        ),
+                        Column::new(
+                            "a".to_string(),
+                            vec![Value::test_int(1), Value::test_int(3)],
+                        ),
+                        Column::new(
+                            "b".to_string(),
+                            vec![Value::test_int(2), Value::test_int(4)],
+                        ),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
+        ]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let streaming = call.has_flag("streaming");
+
+        let value = input.into_value(call.head);
+        let lazy = NuLazyFrame::try_from_value(value)?;
+        let mut polars_lazy = lazy.into_polars();
+
+        if streaming {
+            polars_lazy = polars_lazy.with_streaming(true);
+        }
+        if call.has_flag("no-predicate-pushdown") {
+            polars_lazy = polars_lazy.with_predicate_pushdown(false);
+        }
+        if call.has_flag("no-projection-pushdown") {
+            polars_lazy = polars_lazy.with_projection_pushdown(false);
+        }
+        if call.has_flag("no-slice-pushdown") {
+            polars_lazy = polars_lazy.with_slice_pushdown(false);
+        }
+        if call.has_flag("no-cse") {
+            polars_lazy = polars_lazy.with_common_subplan_elimination(false);
+        }
+        if call.has_flag("no-simplify-expr") {
+            polars_lazy = polars_lazy.with_simplify_expr(false);
+        }
+
+        let row_index: Option<String> = call.get_flag(engine_state, stack, "with-row-index")?;
+        let offset: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "offset")?;
+        let offset = match offset {
+            None => None,
+            Some(offset) if offset.item >= 0 => Some(offset.item as u32),
+            Some(offset) => {
+                return Err(ShellError::IncompatibleParametersSingle(
+                    "Offset must not be negative".into(),
+                    offset.span,
+                ))
+            }
+        };
+        if let Some(name) = row_index {
+            polars_lazy = polars_lazy.with_row_count(&name, offset);
+        }
+
+        let df = polars_lazy.collect().map_err(|e| {
+            if streaming {
+                ShellError::GenericError(
+                    "Not all operations are streaming capable".into(),
+                    "The query plan could not be collected using the streaming engine".into(),
+                    Some(call.head),
+                    Some(e.to_string()),
+                    Vec::new(),
+                )
+            } else {
+                ShellError::GenericError(
+                    "Error collecting lazy dataframe".into(),
+                    e.to_string(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                )
+            }
+        })?;
+
+        let df = NuDataFrame::new(df);
+        Ok(PipelineData::Value(df.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+    use crate::dataframe::lazy::to_lazy::ToLazyFrame;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(vec![Box::new(LazyCollect {}), Box::new(ToLazyFrame {})])
+    }
+}
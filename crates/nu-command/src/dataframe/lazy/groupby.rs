@@ -5,8 +5,6 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
-use polars::prelude::Expr;
-
 #[derive(Clone)]
 pub struct ToLazyGroupBy;
 
@@ -98,6 +96,27 @@ impl Command for ToLazyGroupBy {
                     .into_value(Span::test_data()),
                 ),
             },
+            Example {
+                description: "Group by a computed expression rather than a bare column",
+                example: r#"[[a b]; [1 2] [1 4] [2 6] [2 4]]
+    | to-df
+    | group-by (col a | as "key")
+    | agg (col b | sum | as "b_sum")"#,
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new(
+                            "key".to_string(),
+                            vec![Value::test_int(1), Value::test_int(2)],
+                        ),
+                        Column::new(
+                            "b_sum".to_string(),
+                            vec![Value::test_int(6), Value::test_int(10)],
+                        ),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
         ]
     }
 
@@ -123,17 +142,6 @@ impl Command for ToLazyGroupBy {
         };
         let expressions = NuExpression::extract_exprs(value)?;
 
-        if expressions
-            .iter()
-            .any(|expr| !matches!(expr, Expr::Column(..)))
-        {
-            let value: Value = call.req(engine_state, stack, 0)?;
-            return Err(ShellError::IncompatibleParametersSingle(
-                "Expected only Col expressions".into(),
-                value.span()?,
-            ));
-        }
-
         let value = input.into_value(call.head);
         let lazy = NuLazyFrame::try_from_value(value)?;
         let from_eager = lazy.from_eager;
@@ -0,0 +1,152 @@
+use crate::dataframe::values::{Column, NuDataFrame, NuExpression, NuLazyFrame};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+use polars::prelude::JoinType;
+
+#[derive(Clone)]
+pub struct LazyJoin;
+
+impl Command for LazyJoin {
+    fn name(&self) -> &str {
+        "join"
+    }
+
+    fn usage(&self) -> &str {
+        "Join two lazy dataframes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("other", SyntaxShape::Any, "Lazy dataframe to join with")
+            .required_named(
+                "left-on",
+                SyntaxShape::Table,
+                "Column expressions from the left dataframe to join on",
+                Some('l'),
+            )
+            .required_named(
+                "right-on",
+                SyntaxShape::Table,
+                "Column expressions from the right dataframe to join on",
+                Some('r'),
+            )
+            .named(
+                "how",
+                SyntaxShape::String,
+                "Type of join: inner, left, outer, cross, semi, anti. Defaults to \"inner\"",
+                None,
+            )
+            .category(Category::Custom("lazyframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Join two lazy dataframes on a shared column",
+            example: "[[a b]; [1 2]] | to-lazy | join ([[a c]; [1 3]] | to-lazy) -l [a] -r [a] --how left | collect",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new("a".to_string(), vec![Value::test_int(1)]),
+                    Column::new("b".to_string(), vec![Value::test_int(2)]),
+                    Column::new("c".to_string(), vec![Value::test_int(3)]),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let other: Value = call.req(engine_state, stack, 0)?;
+        let left_on: Vec<Value> = call.req_named(engine_state, stack, "left-on")?;
+        let right_on: Vec<Value> = call.req_named(engine_state, stack, "right-on")?;
+        let how: Option<Spanned<String>> = call.get_flag(engine_state, stack, "how")?;
+
+        if left_on.len() != right_on.len() {
+            return Err(ShellError::IncompatibleParametersSingle(
+                "left-on and right-on must contain the same number of columns".into(),
+                call.head,
+            ));
+        }
+
+        let left_on = NuExpression::extract_exprs(Value::List {
+            vals: left_on,
+            span: call.head,
+        })?;
+        let right_on = NuExpression::extract_exprs(Value::List {
+            vals: right_on,
+            span: call.head,
+        })?;
+
+        let how = match how {
+            None => JoinType::Inner,
+            Some(ref how) => match how.item.as_str() {
+                "inner" => JoinType::Inner,
+                "left" => JoinType::Left,
+                "outer" => JoinType::Outer,
+                "cross" => JoinType::Cross,
+                "semi" => JoinType::Semi,
+                "anti" => JoinType::Anti,
+                _ => {
+                    return Err(ShellError::IncompatibleParametersSingle(
+                        "Expected one of inner, left, outer, cross, semi, anti".into(),
+                        how.span,
+                    ))
+                }
+            },
+        };
+
+        let value = input.into_value(call.head);
+        let lazy = NuLazyFrame::try_from_value(value)?;
+        let from_eager = lazy.from_eager;
+        let other = NuLazyFrame::try_from_value(other)?;
+
+        let joined = lazy
+            .into_polars()
+            .join(other.into_polars(), left_on, right_on, how);
+
+        let lazy = NuLazyFrame::new(from_eager, joined);
+
+        if from_eager {
+            let df = NuDataFrame::try_from(lazy)?;
+            Ok(PipelineData::Value(df.into_value(call.head), None))
+        } else {
+            Ok(PipelineData::Value(lazy.into_value(call.head), None))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+    use crate::dataframe::lazy::collect::LazyCollect;
+    use crate::dataframe::lazy::to_lazy::ToLazyFrame;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(vec![
+            Box::new(LazyJoin {}),
+            Box::new(ToLazyFrame {}),
+            Box::new(LazyCollect {}),
+        ])
+    }
+}
@@ -0,0 +1,253 @@
+use std::path::PathBuf;
+
+use crate::dataframe::values::{Column, NuDataFrame, NuLazyFrame};
+use nu_engine::{glob_from, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+use polars::prelude::{LazyCsvReader, LazyFileListReader};
+
+#[derive(Clone)]
+pub struct LazyScanCsv;
+
+impl Command for LazyScanCsv {
+    fn name(&self) -> &str {
+        "scan-csv"
+    }
+
+    fn usage(&self) -> &str {
+        "Lazily scan a CSV file, building a lazy dataframe without reading it into memory"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "Path to the csv file, glob patterns are supported",
+            )
+            .named(
+                "delimiter",
+                SyntaxShape::String,
+                "Field delimiter, a single ascii character, defaults to ','",
+                Some('d'),
+            )
+            .switch(
+                "no-header",
+                "Indicates the csv file does not have a header row",
+                None,
+            )
+            .named(
+                "infer-schema",
+                SyntaxShape::Int,
+                "Number of rows used to infer the file schema, defaults to 100",
+                None,
+            )
+            .named(
+                "n-rows",
+                SyntaxShape::Int,
+                "Number of rows to fetch, used for previewing a subset of a large file",
+                None,
+            )
+            .named(
+                "with-row-index",
+                SyntaxShape::String,
+                "Name for a row index column added as the first column of the scanned dataframe",
+                None,
+            )
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "Starting offset for the row index column, defaults to 0",
+                None,
+            )
+            .category(Category::Custom("lazyframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Lazily scan a csv file and collect it",
+            example: "scan-csv file.csv | collect",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new(
+                        "a".to_string(),
+                        vec![Value::test_int(1), Value::test_int(3)],
+                    ),
+                    Column::new(
+                        "b".to_string(),
+                        vec![Value::test_int(2), Value::test_int(4)],
+                    ),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Any
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let delimiter: Option<Spanned<String>> = call.get_flag(engine_state, stack, "delimiter")?;
+        let no_header = call.has_flag("no-header");
+        let infer_schema: Option<usize> = call.get_flag(engine_state, stack, "infer-schema")?;
+        let n_rows: Option<usize> = call.get_flag(engine_state, stack, "n-rows")?;
+        let row_count = row_count_from_flags(engine_state, stack, call)?;
+
+        let delimiter = delimiter
+            .map(|delimiter| {
+                if delimiter.item.chars().count() != 1 || !delimiter.item.is_ascii() {
+                    return Err(ShellError::IncompatibleParametersSingle(
+                        "Delimiter must be a single ascii character".into(),
+                        delimiter.span,
+                    ));
+                }
+
+                Ok(delimiter.item.as_bytes()[0])
+            })
+            .transpose()?;
+
+        let paths = scan_paths(engine_state, stack, call)?;
+        let frames = paths
+            .iter()
+            .map(|path| {
+                let mut reader = LazyCsvReader::new(path)
+                    .has_header(!no_header)
+                    .with_infer_schema_length(Some(infer_schema.unwrap_or(100)));
+
+                if let Some(n_rows) = n_rows {
+                    reader = reader.with_n_rows(Some(n_rows));
+                }
+
+                if let Some(delimiter) = delimiter {
+                    reader = reader.with_delimiter(delimiter);
+                }
+
+                reader.finish()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Error scanning csv file".into(),
+                    e.to_string(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        let mut lazy = concat_lazy_frames(frames, call.head)?;
+        if let Some((name, offset)) = row_count {
+            lazy = lazy.with_row_count(&name, Some(offset));
+        }
+
+        let lazy = NuLazyFrame::new(false, lazy);
+        Ok(PipelineData::Value(lazy.into_value(call.head), None))
+    }
+}
+
+/// Resolves the `path` positional argument to the concrete, nu-cwd-relative
+/// paths it matches. Always goes through `glob_from` (rather than handing
+/// Polars the raw glob string) so the files actually scanned match what nu's
+/// own glob resolution decided, even when nu's virtual PWD differs from the
+/// process cwd.
+pub(super) fn scan_paths(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Vec<PathBuf>, ShellError> {
+    let spanned_path: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let cwd = engine_state.cwd(Some(stack))?;
+
+    let (_, paths) = glob_from(&spanned_path, &cwd, call.head, None)?;
+    let paths = paths.collect::<Result<Vec<PathBuf>, ShellError>>()?;
+
+    if paths.is_empty() {
+        return Err(ShellError::FileNotFound(spanned_path.span));
+    }
+
+    Ok(paths)
+}
+
+/// Parses `--with-row-index`/`--offset` into a `(name, offset)` pair. Applied
+/// once, to the fully concatenated `LazyFrame`, so a glob matching several
+/// files still gets one monotonically increasing index rather than every
+/// file restarting from `offset`.
+pub(super) fn row_count_from_flags(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Option<(String, u32)>, ShellError> {
+    let name: Option<String> = call.get_flag(engine_state, stack, "with-row-index")?;
+    let offset: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "offset")?;
+
+    let offset = match offset {
+        None => 0,
+        Some(offset) if offset.item >= 0 => offset.item as u32,
+        Some(offset) => {
+            return Err(ShellError::IncompatibleParametersSingle(
+                "Offset must not be negative".into(),
+                offset.span,
+            ))
+        }
+    };
+
+    Ok(name.map(|name| (name, offset)))
+}
+
+/// Concatenates the `LazyFrame`s built for each resolved path, so a glob
+/// matching several files is scanned as a single lazy dataframe.
+pub(super) fn concat_lazy_frames(
+    frames: Vec<polars::prelude::LazyFrame>,
+    span: nu_protocol::Span,
+) -> Result<polars::prelude::LazyFrame, ShellError> {
+    if frames.len() == 1 {
+        return Ok(frames
+            .into_iter()
+            .next()
+            .expect("frames has exactly one element"));
+    }
+
+    polars::prelude::concat(frames, true, true).map_err(|e| {
+        ShellError::GenericError(
+            "Error concatenating scanned files".into(),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+    use crate::dataframe::lazy::collect::LazyCollect;
+    use std::fs;
+
+    #[test]
+    fn test_examples() {
+        fs::write("file.csv", "a,b\n1,2\n3,4\n").expect("failed to write csv fixture");
+        let result = std::panic::catch_unwind(|| {
+            test_dataframe(vec![Box::new(LazyScanCsv {}), Box::new(LazyCollect {})])
+        });
+        fs::remove_file("file.csv").ok();
+        result.unwrap();
+    }
+}
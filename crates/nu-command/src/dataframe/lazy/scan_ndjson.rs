@@ -0,0 +1,154 @@
+use super::scan_csv::{concat_lazy_frames, row_count_from_flags, scan_paths};
+use crate::dataframe::values::{Column, NuDataFrame, NuLazyFrame};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use polars::prelude::{LazyFileListReader, LazyJsonLineReader};
+
+#[derive(Clone)]
+pub struct LazyScanNdjson;
+
+impl Command for LazyScanNdjson {
+    fn name(&self) -> &str {
+        "scan-ndjson"
+    }
+
+    fn usage(&self) -> &str {
+        "Lazily scan a newline-delimited JSON file, building a lazy dataframe without reading it into memory"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "Path to the ndjson file, glob patterns are supported",
+            )
+            .named(
+                "infer-schema",
+                SyntaxShape::Int,
+                "Number of rows used to infer the file schema, defaults to 100",
+                None,
+            )
+            .named(
+                "n-rows",
+                SyntaxShape::Int,
+                "Number of rows to fetch, used for previewing a subset of a large file",
+                None,
+            )
+            .named(
+                "with-row-index",
+                SyntaxShape::String,
+                "Name for a row index column added as the first column of the scanned dataframe",
+                None,
+            )
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "Starting offset for the row index column, defaults to 0",
+                None,
+            )
+            .category(Category::Custom("lazyframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Lazily scan a ndjson file and collect it",
+            example: "scan-ndjson file.ndjson | collect",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new(
+                        "a".to_string(),
+                        vec![Value::test_int(1), Value::test_int(3)],
+                    ),
+                    Column::new(
+                        "b".to_string(),
+                        vec![Value::test_int(2), Value::test_int(4)],
+                    ),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Any
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let infer_schema: Option<usize> = call.get_flag(engine_state, stack, "infer-schema")?;
+        let n_rows: Option<usize> = call.get_flag(engine_state, stack, "n-rows")?;
+        let row_count = row_count_from_flags(engine_state, stack, call)?;
+        let paths = scan_paths(engine_state, stack, call)?;
+
+        let frames = paths
+            .iter()
+            .map(|path| {
+                let path = path.to_str().ok_or_else(|| {
+                    ShellError::GenericError(
+                        "Error scanning ndjson file".into(),
+                        "path is not valid UTF-8".into(),
+                        Some(call.head),
+                        None,
+                        Vec::new(),
+                    )
+                })?;
+
+                LazyJsonLineReader::new(path.to_string())
+                    .with_infer_schema_length(Some(infer_schema.unwrap_or(100)))
+                    .with_n_rows(n_rows)
+                    .finish()
+                    .map_err(|e| {
+                        ShellError::GenericError(
+                            "Error scanning ndjson file".into(),
+                            e.to_string(),
+                            Some(call.head),
+                            None,
+                            Vec::new(),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut lazy = concat_lazy_frames(frames, call.head)?;
+        if let Some((name, offset)) = row_count {
+            lazy = lazy.with_row_count(&name, Some(offset));
+        }
+
+        let lazy = NuLazyFrame::new(false, lazy);
+        Ok(PipelineData::Value(lazy.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+    use crate::dataframe::lazy::collect::LazyCollect;
+    use std::fs;
+
+    #[test]
+    fn test_examples() {
+        fs::write("file.ndjson", "{\"a\":1,\"b\":2}\n{\"a\":3,\"b\":4}\n")
+            .expect("failed to write ndjson fixture");
+        let result = std::panic::catch_unwind(|| {
+            test_dataframe(vec![Box::new(LazyScanNdjson {}), Box::new(LazyCollect {})])
+        });
+        fs::remove_file("file.ndjson").ok();
+        result.unwrap();
+    }
+}
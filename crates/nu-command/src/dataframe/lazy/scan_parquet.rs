@@ -0,0 +1,148 @@
+use super::scan_csv::{concat_lazy_frames, row_count_from_flags, scan_paths};
+use crate::dataframe::values::{Column, NuDataFrame, NuLazyFrame};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use polars::prelude::{LazyFrame, ScanArgsParquet};
+
+#[derive(Clone)]
+pub struct LazyScanParquet;
+
+impl Command for LazyScanParquet {
+    fn name(&self) -> &str {
+        "scan-parquet"
+    }
+
+    fn usage(&self) -> &str {
+        "Lazily scan a parquet file, building a lazy dataframe without reading it into memory"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "Path to the parquet file, glob patterns are supported",
+            )
+            .named(
+                "n-rows",
+                SyntaxShape::Int,
+                "Number of rows to fetch, used for previewing a subset of a large file",
+                None,
+            )
+            .named(
+                "with-row-index",
+                SyntaxShape::String,
+                "Name for a row index column added as the first column of the scanned dataframe",
+                None,
+            )
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "Starting offset for the row index column, defaults to 0",
+                None,
+            )
+            .category(Category::Custom("lazyframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Lazily scan a parquet file and collect it",
+            example: "scan-parquet file.parquet | collect",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new(
+                        "a".to_string(),
+                        vec![Value::test_int(1), Value::test_int(3)],
+                    ),
+                    Column::new(
+                        "b".to_string(),
+                        vec![Value::test_int(2), Value::test_int(4)],
+                    ),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn input_type(&self) -> Type {
+        Type::Any
+    }
+
+    fn output_type(&self) -> Type {
+        Type::Custom("dataframe".into())
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let n_rows: Option<usize> = call.get_flag(engine_state, stack, "n-rows")?;
+        let row_count = row_count_from_flags(engine_state, stack, call)?;
+        let paths = scan_paths(engine_state, stack, call)?;
+
+        let frames = paths
+            .iter()
+            .map(|path| {
+                let args = ScanArgsParquet {
+                    n_rows,
+                    cache: true,
+                    ..Default::default()
+                };
+                LazyFrame::scan_parquet(path, args)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Error scanning parquet file".into(),
+                    e.to_string(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        let mut lazy = concat_lazy_frames(frames, call.head)?;
+        if let Some((name, offset)) = row_count {
+            lazy = lazy.with_row_count(&name, Some(offset));
+        }
+
+        let lazy = NuLazyFrame::new(false, lazy);
+        Ok(PipelineData::Value(lazy.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+    use crate::dataframe::lazy::collect::LazyCollect;
+    use polars::prelude::{DataFrame, NamedFrom, ParquetWriter, Series};
+    use std::fs;
+
+    #[test]
+    fn test_examples() {
+        let mut df = DataFrame::new(vec![
+            Series::new("a", &[1i64, 3]),
+            Series::new("b", &[2i64, 4]),
+        ])
+        .expect("failed to build parquet fixture dataframe");
+        let file = fs::File::create("file.parquet").expect("failed to create parquet fixture");
+        ParquetWriter::new(file)
+            .finish(&mut df)
+            .expect("failed to write parquet fixture");
+
+        let result = std::panic::catch_unwind(|| {
+            test_dataframe(vec![Box::new(LazyScanParquet {}), Box::new(LazyCollect {})])
+        });
+        fs::remove_file("file.parquet").ok();
+        result.unwrap();
+    }
+}